@@ -1,12 +1,17 @@
 #![no_main]
 #![no_std]
 
+use embassy_futures::select::{select, Either};
 use embassy_nrf::{gpio::Pin, peripherals::TWISPI0, twim::Twim, Peri};
-use embassy_time::{Duration, WithTimeout};
+use embassy_sync::{
+    blocking_mutex::raw::CriticalSectionRawMutex,
+    pubsub::{PubSubChannel, Subscriber},
+};
+use embassy_time::{Duration, Instant, WithTimeout};
 use embedded_graphics::{
     image::{Image, ImageRaw},
     mono_font::MonoTextStyle,
-    pixelcolor::BinaryColor,
+    pixelcolor::{BinaryColor, Rgb565},
     prelude::*,
     primitives::{PrimitiveStyle, Rectangle},
     text::{Alignment, Baseline, Text, TextStyle, TextStyleBuilder, renderer::TextRenderer},
@@ -14,7 +19,9 @@ use embedded_graphics::{
 use rmk::{
     channel::{ControllerSub, CONTROLLER_CHANNEL},
     controller::{Controller, PollingController},
-    event::ControllerEvent,
+    event::{ControllerEvent, KeyboardEvent},
+    input_device::ProcessResult,
+    keycode::KeyCode,
     macros::rmk_central,
     types::modifier::ModifierCombination,
 };
@@ -22,31 +29,343 @@ use ssd1306::{I2CDisplayInterface, Ssd1306Async, mode::BufferedGraphicsModeAsync
 
 const LAYER_NAMES: [&str; 8] = ["BASE", "NAV", "SYM", "NUM", "ACC", "COM", "GAME", "GAME"];
 
-struct Graphics<'a> {
-    character_style: MonoTextStyle<'a, BinaryColor>,
-    character_smaller: MonoTextStyle<'a, BinaryColor>,
-    fill_style: PrimitiveStyle<BinaryColor>,
-    stroke_style: PrimitiveStyle<BinaryColor>,
+/// Number of modifier-icon slots drawn on the status layout.
+const MODIFIER_SLOTS: usize = 4;
+
+/// Height of a single SSD1306 page, in pixels.
+const PAGE_HEIGHT: i32 = 8;
+
+/// Width, in pixels, of the boot-splash logo asset.
+const LOGO_WIDTH: u32 = 48;
+
+/// What the controller shows once the panel has been idle past its timeout.
+enum ScreenSaver {
+    /// Drift the whole composition a few pixels each second so no pixel stays lit.
+    Drift,
+    /// Blank the panel entirely until the next event.
+    Blank,
+}
+
+/// Peak drift, in pixels, applied to the composition while the screensaver runs.
+const DRIFT_RANGE: i32 = 4;
+
+/// Triangle wave in `[-range, range]` advancing one step per unit of `t`.
+fn triangle(t: i32, range: i32) -> i32 {
+    let span = range * 2;
+    let phase = t.rem_euclid(span * 2);
+    (if phase <= span { phase } else { span * 2 - phase }) - range
+}
+
+/// Write `n` as decimal into the tail of `buf`, returning the slice holding it.
+fn fmt_u32(mut n: u32, buf: &mut [u8; 10]) -> &str {
+    let mut i = buf.len();
+    if n == 0 {
+        i -= 1;
+        buf[i] = b'0';
+    }
+    while n > 0 {
+        i -= 1;
+        buf[i] = b'0' + (n % 10) as u8;
+        n /= 10;
+    }
+    core::str::from_utf8(&buf[i..]).unwrap_or("")
+}
+
+/// Write `n` (clamped to 100) as a `"NN%"` string into the tail of `buf`.
+fn fmt_percent(n: u8, buf: &mut [u8; 4]) -> &str {
+    let mut v = n.min(100) as u32;
+    let mut i = buf.len();
+    i -= 1;
+    buf[i] = b'%';
+    if v == 0 {
+        i -= 1;
+        buf[i] = b'0';
+    }
+    while v > 0 {
+        i -= 1;
+        buf[i] = b'0' + (v % 10) as u8;
+        v /= 10;
+    }
+    core::str::from_utf8(&buf[i..]).unwrap_or("")
+}
+
+/// Raw-HID report id reserved for host telemetry packets.
+const HOST_STATS_REPORT_ID: u8 = 0xB0;
+/// Packet format revision; a companion host daemon stamps this so the firmware
+/// can reject layouts it does not understand.
+const HOST_STATS_VERSION: u8 = 1;
+/// Bytes reserved for the trailing UTF-8 status line (now-playing/notification).
+const HOST_STATS_TEXT_LEN: usize = 28;
+
+/// Decoded host telemetry pushed from a companion daemon over raw HID.
+///
+/// The wire packet is a fixed-size report:
+///
+/// | byte  | field                                   |
+/// |-------|-----------------------------------------|
+/// | 0     | report id ([`HOST_STATS_REPORT_ID`])    |
+/// | 1     | format version ([`HOST_STATS_VERSION`]) |
+/// | 2     | CPU load, percent                       |
+/// | 3     | RAM usage, percent                      |
+/// | 4..32 | NUL-padded UTF-8 status line            |
+#[derive(Clone, Copy)]
+struct HostStats {
+    cpu: u8,
+    ram: u8,
+    text: [u8; HOST_STATS_TEXT_LEN],
+}
+
+impl HostStats {
+    /// Decode a raw-HID `report`, returning `None` for any packet whose id or
+    /// version is not recognised so unknown reports are silently ignored.
+    fn from_report(report: &[u8]) -> Option<Self> {
+        if *report.first()? != HOST_STATS_REPORT_ID || *report.get(1)? != HOST_STATS_VERSION {
+            return None;
+        }
+        let cpu = (*report.get(2)?).min(100);
+        let ram = (*report.get(3)?).min(100);
+        let mut text = [0; HOST_STATS_TEXT_LEN];
+        text.copy_from_slice(report.get(4..4 + HOST_STATS_TEXT_LEN)?);
+        Some(Self { cpu, ram, text })
+    }
+
+    /// The status line as a string, stopping at the first NUL padding byte.
+    fn text(&self) -> &str {
+        let end = self.text.iter().position(|&b| b == 0).unwrap_or(self.text.len());
+        core::str::from_utf8(&self.text[..end]).unwrap_or("")
+    }
+}
+
+/// Events that originate inside this firmware rather than from RMK's HID stack.
+///
+/// [`rmk::event::ControllerEvent`] is defined upstream and closed to downstream
+/// variants, so the host-telemetry/keyboard-local events ride their own channel
+/// and are merged into the controller's event loop alongside
+/// [`CONTROLLER_CHANNEL`].
+enum SidebarEvent {
+    /// Host telemetry decoded from a raw-HID report.
+    HostStats(HostStats),
+    /// A single keypress, feeding the keypress-counter page.
+    KeyPress,
+    /// Advance the view manager to the next cycleable page.
+    CycleScreen,
+}
+
+/// Sidebar event bus feeding the single [`DisplayController`]. Events are sent
+/// through immediate publishers, which take no publisher slot; when the queue
+/// is full the oldest event is dropped.
+static SIDEBAR_CHANNEL: PubSubChannel<CriticalSectionRawMutex, SidebarEvent, 4, 1, 2> =
+    PubSubChannel::new();
+
+type SidebarSub = Subscriber<'static, CriticalSectionRawMutex, SidebarEvent, 4, 1, 2>;
+
+/// Decode a raw-HID `report` and, if it is a host-telemetry packet, publish it
+/// onto [`SIDEBAR_CHANNEL`] so the [`DisplayController`] can pick it up. The
+/// `host_stats` raw-HID hook in [`keyboard_central`] feeds it every output
+/// report received on the vendor endpoint; unrecognised packets are dropped.
+pub fn route_host_stats_report(report: &[u8]) {
+    if let Some(stats) = HostStats::from_report(report) {
+        SIDEBAR_CHANNEL
+            .immediate_publisher()
+            .publish_immediate(SidebarEvent::HostStats(stats));
+    }
+}
+
+/// Record a single keypress for the WPM/keypress-counter page. The
+/// `keypress_counter` processor in [`keyboard_central`] calls this for every
+/// resolved key press so the counter tracks real typing.
+pub fn record_keypress() {
+    SIDEBAR_CHANNEL
+        .immediate_publisher()
+        .publish_immediate(SidebarEvent::KeyPress);
+}
+
+/// Advance the view manager to the next cycleable page. The
+/// `cycle_screen_action` combo in [`keyboard_central`] calls this so users can
+/// flip between the status, battery, and keypress pages.
+pub fn cycle_screen() {
+    SIDEBAR_CHANNEL
+        .immediate_publisher()
+        .publish_immediate(SidebarEvent::CycleScreen);
+}
+
+fn modifier_active(modifiers: &ModifierCombination, slot: usize) -> bool {
+    match slot {
+        0 => modifiers.left_shift() || modifiers.right_shift(),
+        1 => modifiers.left_ctrl() || modifiers.right_ctrl(),
+        2 => modifiers.left_alt() || modifiers.right_alt(),
+        _ => modifiers.left_gui() || modifiers.right_gui(),
+    }
+}
+
+/// A concrete screen panel the [`DisplayController`] draws onto.
+///
+/// The layout code is written once against [`DrawTarget`] and this trait's size
+/// and colour metrics, so the same controller drives the mono SSD1306 panel and
+/// an SPI colour TFT. An implementation owns its bus/pins, brings the panel up
+/// in [`init`](Self::init), and pushes the composed frame in
+/// [`flush`](Self::flush) (a no-op for immediate-mode panels).
+trait DisplayBackend {
+    /// Pixel colour model of the panel. Must be convertible from
+    /// [`BinaryColor`] so the 1-bpp icon/logo assets can be colour-converted
+    /// onto a colour panel.
+    type Color: PixelColor + From<BinaryColor>;
+    /// The [`DrawTarget`] handed to the layout code once initialised.
+    type Target: DrawTarget<Color = Self::Color, Error = Self::Error>;
+    /// Error surfaced by drawing and flushing.
+    type Error;
+
+    /// Foreground ("lit") colour.
+    const ON: Self::Color;
+    /// Background ("cleared") colour.
+    const OFF: Self::Color;
+
+    /// Panel size in pixels.
+    fn size(&self) -> Size;
+
+    /// Bring the panel up and return a ready draw target, or `None` on failure.
+    async fn init(&mut self) -> Option<Self::Target>;
+
+    /// Push the composed frame to the panel. Backends that track dirty pixels
+    /// (the SSD1306's buffered mode) transmit only the pages touched since the
+    /// last flush, so callers keep transfers small by redrawing just the
+    /// regions that changed.
+    async fn flush(&mut self, target: &mut Self::Target) -> Result<(), Self::Error>;
+}
+
+type Ssd1306Display = Ssd1306Async<
+    I2CInterface<Twim<'static>>,
+    DisplaySize128x32,
+    BufferedGraphicsModeAsync<DisplaySize128x32>,
+>;
+
+/// Mono 128×32 SSD1306 panel on the central half's TWI bus.
+struct Ssd1306Backend<SDA: Pin, SCL: Pin> {
+    twim: Peri<'static, TWISPI0>,
+    sda: Peri<'static, SDA>,
+    scl: Peri<'static, SCL>,
+}
+
+impl<SDA: Pin, SCL: Pin> Ssd1306Backend<SDA, SCL> {
+    fn new(twim: Peri<'static, TWISPI0>, sda: Peri<'static, SDA>, scl: Peri<'static, SCL>) -> Self {
+        Self { twim, sda, scl }
+    }
+}
+
+bind_interrupts!(struct MyIrqs {
+    TWISPI0 => embassy_nrf::twim::InterruptHandler<embassy_nrf::peripherals::TWISPI0>;
+});
+
+impl<SDA: Pin, SCL: Pin> DisplayBackend for Ssd1306Backend<SDA, SCL> {
+    type Color = BinaryColor;
+    type Target = Ssd1306Display;
+    type Error = <Ssd1306Display as DrawTarget>::Error;
+
+    const ON: BinaryColor = BinaryColor::On;
+    const OFF: BinaryColor = BinaryColor::Off;
+
+    fn size(&self) -> Size {
+        Size::new(128, 32)
+    }
+
+    async fn init(&mut self) -> Option<Self::Target> {
+        let i2c = unsafe {
+            Twim::new(
+                self.twim.clone_unchecked(),
+                MyIrqs,
+                self.sda.clone_unchecked(),
+                self.scl.clone_unchecked(),
+                Default::default(),
+                &mut [],
+            )
+        };
+        let interface = I2CDisplayInterface::new(i2c);
+        let mut display = Ssd1306Async::new(interface, DisplaySize128x32, DisplayRotation::Rotate0)
+            .into_buffered_graphics_mode();
+        match display.init().with_timeout(Duration::from_secs(1)).await {
+            Ok(Ok(_)) => Some(display),
+            _ => None,
+        }
+    }
+
+    async fn flush(&mut self, target: &mut Self::Target) -> Result<(), Self::Error> {
+        target.flush().await
+    }
+}
+
+/// SPI colour TFT backend (ST7735/ILI9341) over an `embedded-graphics`
+/// [`DrawTarget`].
+///
+/// The board bring-up code constructs the concrete `mipidsi`/`st7735-lcd`
+/// display — the get_display/release_display split — and hands the ready target
+/// here; [`init`](DisplayBackend::init) serves it to the controller. Because the
+/// TFT is an immediate-mode driver each primitive lands on the glass as it is
+/// drawn, so [`flush`](DisplayBackend::flush) has nothing left to do.
+struct TftBackend<T> {
+    target: Option<T>,
+    size: Size,
+}
+
+impl<T> TftBackend<T> {
+    fn new(target: T, size: Size) -> Self {
+        Self {
+            target: Some(target),
+            size,
+        }
+    }
+}
+
+impl<T> DisplayBackend for TftBackend<T>
+where
+    T: DrawTarget<Color = Rgb565>,
+{
+    type Color = Rgb565;
+    type Target = T;
+    type Error = T::Error;
+
+    const ON: Rgb565 = Rgb565::WHITE;
+    const OFF: Rgb565 = Rgb565::BLACK;
+
+    fn size(&self) -> Size {
+        self.size
+    }
+
+    async fn init(&mut self) -> Option<Self::Target> {
+        self.target.take()
+    }
+
+    async fn flush(&mut self, _target: &mut Self::Target) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+struct Graphics<'a, C: PixelColor> {
+    character_style: MonoTextStyle<'a, C>,
+    character_smaller: MonoTextStyle<'a, C>,
+    fill_style: PrimitiveStyle<C>,
+    clear_style: PrimitiveStyle<C>,
+    stroke_style: PrimitiveStyle<C>,
     centered_style: TextStyle,
+    bounding_box: Rectangle,
     layer_center: Point,
+    // The icon/logo assets are 1-bpp bitmaps regardless of the panel's colour
+    // model; they are colour-converted to `C` when drawn.
     raw_shift: ImageRaw<'a, BinaryColor>,
     raw_ctrl: ImageRaw<'a, BinaryColor>,
     raw_alt: ImageRaw<'a, BinaryColor>,
     raw_gui: ImageRaw<'a, BinaryColor>,
+    raw_logo: ImageRaw<'a, BinaryColor>,
 }
 
-impl<'a> Graphics<'a> {
-    fn new(bounding_box: Rectangle) -> Self {
-        let character_style = MonoTextStyle::new(
-            &embedded_graphics::mono_font::ascii::FONT_9X18,
-            BinaryColor::On,
-        );
-        let character_smaller = MonoTextStyle::new(
-            &embedded_graphics::mono_font::ascii::FONT_6X10,
-            BinaryColor::On,
-        );
-        let fill_style = PrimitiveStyle::with_fill(BinaryColor::On);
-        let stroke_style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
+impl<'a, C: PixelColor> Graphics<'a, C> {
+    fn new(bounding_box: Rectangle, on: C, off: C) -> Self {
+        let character_style =
+            MonoTextStyle::new(&embedded_graphics::mono_font::ascii::FONT_9X18, on);
+        let character_smaller =
+            MonoTextStyle::new(&embedded_graphics::mono_font::ascii::FONT_6X10, on);
+        let fill_style = PrimitiveStyle::with_fill(on);
+        let clear_style = PrimitiveStyle::with_fill(off);
+        let stroke_style = PrimitiveStyle::with_stroke(on, 1);
         let centered_style = TextStyleBuilder::new()
             .baseline(Baseline::Middle)
             .alignment(Alignment::Center)
@@ -63,245 +382,758 @@ impl<'a> Graphics<'a> {
         let raw_ctrl = ImageRaw::<BinaryColor>::new(include_bytes!("./display/ctrl.raw"), 12);
         let raw_alt = ImageRaw::<BinaryColor>::new(include_bytes!("./display/alt.raw"), 12);
         let raw_gui = ImageRaw::<BinaryColor>::new(include_bytes!("./display/gui.raw"), 12);
+        let raw_logo = ImageRaw::<BinaryColor>::new(include_bytes!("./display/logo.raw"), LOGO_WIDTH);
 
         Self {
             character_style,
             character_smaller,
             fill_style,
+            clear_style,
             stroke_style,
             centered_style,
+            bounding_box,
             layer_center,
             raw_shift,
             raw_ctrl,
             raw_alt,
             raw_gui,
+            raw_logo,
+        }
+    }
+
+    fn center(&self) -> Point {
+        self.bounding_box.center()
+    }
+
+    fn modifier_raw(&self, slot: usize) -> &ImageRaw<'a, BinaryColor> {
+        match slot {
+            0 => &self.raw_shift,
+            1 => &self.raw_ctrl,
+            2 => &self.raw_alt,
+            _ => &self.raw_gui,
         }
     }
+
+    /// Bounding box of the stacked layer-name band on the left of the panel.
+    fn layer_region(&self) -> Rectangle {
+        Rectangle::new(
+            Point::zero(),
+            Size::new(self.layer_center.x as u32 * 2, self.bounding_box.size.height),
+        )
+    }
+
+    /// Bounding box of a single modifier-icon slot (icon plus its underline).
+    fn modifier_region(&self, slot: usize) -> Rectangle {
+        Rectangle::with_center(
+            Point::new(self.center().x + 15 * slot as i32, self.center().y),
+            Size::new(14, self.bounding_box.size.height),
+        )
+    }
+
+    /// Bounding box of the battery outline and fill on the right of the panel.
+    fn battery_region(&self) -> Rectangle {
+        let w = self.bounding_box.size.width as i32;
+        let h = self.bounding_box.size.height as i32;
+        Rectangle::with_corners(Point::new(w - 5, 0), Point::new(w - 1, h - 1))
+    }
 }
 
-type Display = Ssd1306Async<
-    I2CInterface<Twim<'static>>,
-    DisplaySize128x32,
-    BufferedGraphicsModeAsync<DisplaySize128x32>,
->;
+/// Accumulates the screen area touched since the last flush, snapped out to
+/// SSD1306 page boundaries. [`DisplayController::draw_status`] uses it only to
+/// tell whether anything changed; the rectangle is not passed to the driver,
+/// which transmits the window it tracks itself.
+#[derive(Default)]
+struct DirtyRegion {
+    bounds: Option<Rectangle>,
+}
 
-struct DisplayConfig<SDA: Pin, SCL: Pin> {
-    twim: Peri<'static, TWISPI0>,
-    sda: Peri<'static, SDA>,
-    scl: Peri<'static, SCL>,
+impl DirtyRegion {
+    /// Add `rect` to the accumulated bounding box, expanding it vertically to
+    /// the enclosing 8-pixel pages.
+    fn add(&mut self, rect: Rectangle) {
+        let top = (rect.top_left.y / PAGE_HEIGHT) * PAGE_HEIGHT;
+        let bottom = ((rect.top_left.y + rect.size.height as i32 + PAGE_HEIGHT - 1) / PAGE_HEIGHT)
+            * PAGE_HEIGHT;
+        let snapped = Rectangle::with_corners(
+            Point::new(rect.top_left.x, top),
+            Point::new(rect.top_left.x + rect.size.width as i32 - 1, bottom - 1),
+        );
+        self.bounds = Some(match self.bounds {
+            Some(current) => {
+                let tl = Point::new(
+                    current.top_left.x.min(snapped.top_left.x),
+                    current.top_left.y.min(snapped.top_left.y),
+                );
+                let br = Point::new(
+                    (current.top_left.x + current.size.width as i32)
+                        .max(snapped.top_left.x + snapped.size.width as i32)
+                        - 1,
+                    (current.top_left.y + current.size.height as i32)
+                        .max(snapped.top_left.y + snapped.size.height as i32)
+                        - 1,
+                );
+                Rectangle::with_corners(tl, br)
+            }
+            None => snapped,
+        });
+    }
+
+    /// The accumulated, page-snapped bounding box, or `None` for an unchanged
+    /// frame.
+    fn bounds(&self) -> Option<Rectangle> {
+        self.bounds
+    }
 }
 
-struct DisplayController<'a, SDA: Pin, SCL: Pin> {
-    sub: ControllerSub,
-    config: DisplayConfig<SDA, SCL>,
-    display: Option<Display>,
+/// Snapshot of everything a [`Screen`] may draw, owned by the controller and
+/// passed to whichever screen is active.
+struct ViewState {
     layer: u8,
     modifiers: ModifierCombination,
     battery: u8,
-    graphics: Graphics<'a>,
+    keypresses: u32,
 }
 
-bind_interrupts!(struct MyIrqs {
-    TWISPI0 => embassy_nrf::twim::InterruptHandler<embassy_nrf::peripherals::TWISPI0>;
-});
+/// Compact comparison key used to skip redrawing an unchanged frame.
+#[derive(Clone, Copy, PartialEq)]
+struct ViewKey {
+    layer: u8,
+    modifiers: u8,
+    battery: u8,
+    keypresses: u32,
+    active: usize,
+}
+
+impl ViewKey {
+    fn new(state: &ViewState, active: usize) -> Self {
+        let mut modifiers = 0;
+        for slot in 0..MODIFIER_SLOTS {
+            if modifier_active(&state.modifiers, slot) {
+                modifiers |= 1 << slot;
+            }
+        }
+        Self {
+            layer: state.layer,
+            modifiers,
+            battery: state.battery,
+            keypresses: state.keypresses,
+            active,
+        }
+    }
+}
+
+/// Draw the stacked layer-name band on the left of the panel.
+fn draw_layer<D: DrawTarget>(
+    target: &mut D,
+    g: &Graphics<'_, D::Color>,
+    layer: u8,
+) -> Result<(), D::Error> {
+    let offset = Point::new(0, g.character_style.font.character_size.height as i32 / 3 * 2);
+
+    if layer > 0 {
+        Text::with_text_style(
+            LAYER_NAMES[layer as usize - 1],
+            g.layer_center - offset,
+            g.character_smaller,
+            g.centered_style,
+        )
+        .draw(target)?;
+    }
+
+    if layer < LAYER_NAMES.len() as u8 - 1 {
+        Text::with_text_style(
+            LAYER_NAMES[layer as usize + 1],
+            g.layer_center + offset,
+            g.character_smaller,
+            g.centered_style,
+        )
+        .draw(target)?;
+    }
+
+    Text::with_text_style(
+        LAYER_NAMES[layer as usize],
+        g.layer_center,
+        g.character_style,
+        g.centered_style,
+    )
+    .draw(target)?;
 
-impl<'a, SDA, SCL> DisplayController<'a, SDA, SCL>
+    Ok(())
+}
+
+/// Draw a single modifier-icon slot, raised and underlined when active.
+fn draw_modifier<D: DrawTarget>(
+    target: &mut D,
+    g: &Graphics<'_, D::Color>,
+    modifiers: &ModifierCombination,
+    slot: usize,
+) -> Result<(), D::Error>
 where
-    SDA: Pin,
-    SCL: Pin,
+    BinaryColor: Into<D::Color>,
 {
-    fn new(twim: Peri<'static, TWISPI0>, sda: Peri<'static, SDA>, scl: Peri<'static, SCL>) -> Self {
+    let active = modifier_active(modifiers, slot);
+    let offset = Point::new(15 * slot as i32, 0);
+
+    Image::with_center(g.modifier_raw(slot), g.center() + offset)
+        .translate(Point::new(0, active as i32 * -4))
+        .draw(&mut target.color_converted())?;
+
+    if active {
+        Rectangle::with_center(g.center() + offset + Point::new(0, 5), Size::new(12, 2))
+            .into_styled(g.fill_style)
+            .draw(target)?;
+    }
+
+    Ok(())
+}
+
+/// Draw the vertical battery gauge on the right edge of the panel.
+fn draw_battery<D: DrawTarget>(
+    target: &mut D,
+    g: &Graphics<'_, D::Color>,
+    battery: u8,
+) -> Result<(), D::Error> {
+    let w = g.bounding_box.size.width as i32;
+    let h = g.bounding_box.size.height as i32;
+
+    Rectangle::with_corners(Point::new(w - 5, 0), Point::new(w - 1, h - 1))
+        .into_styled(g.stroke_style)
+        .draw(target)?;
+
+    Rectangle::with_corners(
+        Point::new(w - 4, h - (battery as i32 * h) / 100),
+        Point::new(w - 2, h - 1),
+    )
+    .into_styled(g.fill_style)
+    .draw(target)?;
+
+    Ok(())
+}
+
+/// A page the view manager can show. Each screen is stateless and renders from
+/// the shared [`ViewState`]; the controller clears the frame before calling it.
+trait Screen<B: DisplayBackend> {
+    fn draw(
+        &self,
+        target: &mut B::Target,
+        graphics: &Graphics<'_, B::Color>,
+        state: &ViewState,
+    ) -> Result<(), B::Error>;
+}
+
+/// Layer name, active modifiers, and battery gauge — the default page.
+struct StatusScreen;
+
+impl<B: DisplayBackend> Screen<B> for StatusScreen {
+    fn draw(
+        &self,
+        target: &mut B::Target,
+        graphics: &Graphics<'_, B::Color>,
+        state: &ViewState,
+    ) -> Result<(), B::Error> {
+        draw_layer(target, graphics, state.layer)?;
+        for slot in 0..MODIFIER_SLOTS {
+            draw_modifier(target, graphics, &state.modifiers, slot)?;
+        }
+        draw_battery(target, graphics, state.battery)
+    }
+}
+
+/// Large battery percentage plus a horizontal charge bar.
+struct BatteryScreen;
+
+impl<B: DisplayBackend> Screen<B> for BatteryScreen {
+    fn draw(
+        &self,
+        target: &mut B::Target,
+        graphics: &Graphics<'_, B::Color>,
+        state: &ViewState,
+    ) -> Result<(), B::Error> {
+        let mut buf = [0; 4];
+        Text::with_text_style(
+            fmt_percent(state.battery, &mut buf),
+            graphics.center() - Point::new(0, 4),
+            graphics.character_style,
+            graphics.centered_style,
+        )
+        .draw(target)?;
+
+        let w = graphics.bounding_box.size.width as i32;
+        let h = graphics.bounding_box.size.height as i32;
+        let y = h - 6;
+        Rectangle::with_corners(Point::new(8, y), Point::new(w - 8, h - 2))
+            .into_styled(graphics.stroke_style)
+            .draw(target)?;
+        let fill = (w - 18) * state.battery.min(100) as i32 / 100;
+        if fill > 0 {
+            Rectangle::with_corners(Point::new(9, y + 1), Point::new(9 + fill, h - 3))
+                .into_styled(graphics.fill_style)
+                .draw(target)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Running keypress counter.
+struct WpmScreen;
+
+impl<B: DisplayBackend> Screen<B> for WpmScreen {
+    fn draw(
+        &self,
+        target: &mut B::Target,
+        graphics: &Graphics<'_, B::Color>,
+        state: &ViewState,
+    ) -> Result<(), B::Error> {
+        let mut buf = [0; 10];
+        Text::with_text_style(
+            fmt_u32(state.keypresses, &mut buf),
+            graphics.center() - Point::new(0, 4),
+            graphics.character_style,
+            graphics.centered_style,
+        )
+        .draw(target)?;
+        Text::with_text_style(
+            "KEYS",
+            graphics.center() + Point::new(0, 9),
+            graphics.character_smaller,
+            graphics.centered_style,
+        )
+        .draw(target)?;
+
+        Ok(())
+    }
+}
+
+struct DisplayController<'a, B: DisplayBackend> {
+    sub: ControllerSub,
+    sidebar: SidebarSub,
+    backend: B,
+    target: Option<B::Target>,
+    layer: u8,
+    modifiers: ModifierCombination,
+    battery: u8,
+    keypresses: u32,
+    active: usize,
+    screens: [&'static dyn Screen<B>; 3],
+    force_redraw: bool,
+    drawn_key: Option<ViewKey>,
+    drawn_layer: u8,
+    drawn_modifiers: ModifierCombination,
+    drawn_battery: u8,
+    splash_duration: Duration,
+    splash_started: Option<Instant>,
+    booted: bool,
+    idle_timeout: Duration,
+    screensaver: ScreenSaver,
+    last_activity: Instant,
+    screensaver_active: bool,
+    drawn_offset: Option<Point>,
+    host_stats: Option<HostStats>,
+    show_host: bool,
+    scroll: i32,
+    graphics: Graphics<'a, B::Color>,
+}
+
+const STATUS_SCREEN: StatusScreen = StatusScreen;
+const BATTERY_SCREEN: BatteryScreen = BatteryScreen;
+const WPM_SCREEN: WpmScreen = WpmScreen;
+
+impl<'a, B: DisplayBackend> DisplayController<'a, B> {
+    fn new(
+        backend: B,
+        idle_timeout: Duration,
+        screensaver: ScreenSaver,
+        splash_duration: Duration,
+    ) -> Self {
+        let bounding_box = Rectangle::new(Point::zero(), backend.size());
         Self {
             sub: CONTROLLER_CHANNEL.subscriber().unwrap(),
-            config: DisplayConfig { twim, sda, scl },
-            display: None,
+            sidebar: SIDEBAR_CHANNEL.subscriber().unwrap(),
+            backend,
+            target: None,
             layer: 0,
             modifiers: ModifierCombination::new(),
             battery: 0,
-            graphics: Graphics::new(Rectangle::new(Point::zero(), Size::new(128, 32))),
+            keypresses: 0,
+            active: 0,
+            screens: [&STATUS_SCREEN, &BATTERY_SCREEN, &WPM_SCREEN],
+            force_redraw: true,
+            drawn_key: None,
+            drawn_layer: 0,
+            drawn_modifiers: ModifierCombination::new(),
+            drawn_battery: 0,
+            splash_duration,
+            splash_started: None,
+            booted: false,
+            idle_timeout,
+            screensaver,
+            last_activity: Instant::now(),
+            screensaver_active: false,
+            drawn_offset: None,
+            host_stats: None,
+            show_host: false,
+            scroll: 0,
+            graphics: Graphics::new(bounding_box, B::ON, B::OFF),
         }
     }
 
-    async fn draw(&mut self, display: &mut Display) -> Result<(), <Display as DrawTarget>::Error> {
-        display.clear_buffer();
+    /// Handle a keyboard-local [`SidebarEvent`], which rmk's upstream
+    /// [`ControllerEvent`] enum cannot carry. Mirrors [`process_event`] in
+    /// resetting the idle timer and rendering when the queue is shallow.
+    ///
+    /// [`process_event`]: Controller::process_event
+    async fn process_sidebar(&mut self, event: SidebarEvent) {
+        match event {
+            SidebarEvent::HostStats(stats) => {
+                self.host_stats = Some(stats);
+                self.show_host = true;
+            }
+            SidebarEvent::KeyPress => {
+                self.keypresses = self.keypresses.wrapping_add(1);
+            }
+            SidebarEvent::CycleScreen => {
+                self.active = (self.active + 1) % self.screens.len();
+                self.leave_host();
+                self.force_redraw = true;
+            }
+        }
+
+        self.last_activity = Instant::now();
 
-        if self.layer > 0 {
-            Text::with_text_style(
-                LAYER_NAMES[self.layer as usize - 1],
-                self.graphics.layer_center
-                    - Point::new(
-                        0,
-                        self.graphics.character_style.font.character_size.height as i32 / 3 * 2,
-                    ),
-                self.graphics.character_smaller,
-                self.graphics.centered_style,
-            )
-            .draw(display)?;
+        if self.sidebar.len() < 2 {
+            self.update().await;
         }
+    }
 
-        if self.layer < LAYER_NAMES.len() as u8 - 1 {
-            Text::with_text_style(
-                LAYER_NAMES[self.layer as usize + 1],
-                self.graphics.layer_center
-                    + Point::new(
-                        0,
-                        self.graphics.character_style.font.character_size.height as i32 / 3 * 2,
-                    ),
-                self.graphics.character_smaller,
-                self.graphics.centered_style,
-            )
-            .draw(display)?;
+    /// Switch back to the cycleable pages, forcing a redraw if the
+    /// host-telemetry screen was showing.
+    fn leave_host(&mut self) {
+        if self.show_host {
+            self.show_host = false;
+            self.force_redraw = true;
         }
+    }
 
-        Text::with_text_style(
-            LAYER_NAMES[self.layer as usize],
-            self.graphics.layer_center,
-            self.graphics.character_style,
-            self.graphics.centered_style,
-        )
-        .draw(display)?;
+    /// Global pixel offset applied to the composition while drifting, derived
+    /// from how long the panel has been idle. X and Y use different periods so
+    /// the content traces a gentle bounce within the panel.
+    fn screensaver_offset(&self) -> Point {
+        let secs = self.last_activity.elapsed().as_secs() as i32;
+        Point::new(triangle(secs, DRIFT_RANGE), triangle(secs, DRIFT_RANGE / 2))
+    }
 
-        Image::with_center(
-            &self.graphics.raw_shift,
-            display.bounding_box().center() + Point::new(0, 0),
-        )
-        .translate(Point::new(
-            0,
-            (self.modifiers.left_shift() || self.modifiers.right_shift()) as i32 * -4,
-        ))
-        .draw(display)?;
-        if self.modifiers.left_shift() || self.modifiers.right_shift() {
-            Rectangle::with_center(
-                display.bounding_box().center() + Point::new(0, 5),
-                Size::new(12, 2),
-            )
-            .into_styled(self.graphics.fill_style)
-            .draw(display)?;
+    /// Render the burn-in screensaver: either a blank panel or the status
+    /// composition displaced by [`Self::screensaver_offset`]. Only reflushes when
+    /// the offset actually advances so no pixel is lit continuously.
+    async fn draw_screensaver(&mut self, display: &mut B::Target) -> Result<(), B::Error> {
+        let offset = match self.screensaver {
+            ScreenSaver::Blank => Point::zero(),
+            ScreenSaver::Drift => self.screensaver_offset(),
+        };
+        if self.drawn_offset == Some(offset) {
+            return Ok(());
         }
-        Image::with_center(
-            &self.graphics.raw_ctrl,
-            display.bounding_box().center() + Point::new(15, 0),
-        )
-        .translate(Point::new(
-            0,
-            (self.modifiers.left_ctrl() || self.modifiers.right_ctrl()) as i32 * -4,
-        ))
-        .draw(display)?;
-        if self.modifiers.left_ctrl() || self.modifiers.right_ctrl() {
-            Rectangle::with_center(
-                display.bounding_box().center() + Point::new(15, 5),
-                Size::new(12, 2),
-            )
-            .into_styled(self.graphics.fill_style)
-            .draw(display)?;
+
+        display.clear(B::OFF)?;
+        if let ScreenSaver::Drift = self.screensaver {
+            let mut target = display.translated(offset);
+            draw_layer(&mut target, &self.graphics, self.layer)?;
+            for slot in 0..MODIFIER_SLOTS {
+                draw_modifier(&mut target, &self.graphics, &self.modifiers, slot)?;
+            }
+            draw_battery(&mut target, &self.graphics, self.battery)?;
         }
+        self.drawn_offset = Some(offset);
+
+        self.backend.flush(display).await
+    }
+
+    /// Render the animated boot splash: the logo bobbing gently for the
+    /// configured duration before the status screen takes over.
+    async fn draw_splash(&mut self, display: &mut B::Target) -> Result<(), B::Error> {
+        let ticks = self
+            .splash_started
+            .map(|s| (s.elapsed().as_millis() / 125) as i32)
+            .unwrap_or(0);
+
+        display.clear(B::OFF)?;
         Image::with_center(
-            &self.graphics.raw_alt,
-            display.bounding_box().center() + Point::new(30, 0),
+            &self.graphics.raw_logo,
+            self.graphics.center() + Point::new(0, triangle(ticks, 3)),
         )
-        .translate(Point::new(
-            0,
-            (self.modifiers.left_alt() || self.modifiers.right_alt()) as i32 * -4,
-        ))
-        .draw(display)?;
-        if self.modifiers.left_alt() || self.modifiers.right_alt() {
-            Rectangle::with_center(
-                display.bounding_box().center() + Point::new(30, 5),
-                Size::new(12, 2),
-            )
-            .into_styled(self.graphics.fill_style)
-            .draw(display)?;
-        }
-        Image::with_center(
-            &self.graphics.raw_gui,
-            display.bounding_box().center() + Point::new(45, 0),
+        .draw(&mut display.color_converted())?;
+
+        self.backend.flush(display).await
+    }
+
+    /// Draw a labelled horizontal bar gauge filled to `percent` at row `y`.
+    fn draw_host_gauge<D>(
+        &self,
+        display: &mut D,
+        label: &str,
+        percent: u8,
+        y: i32,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = B::Color>,
+    {
+        Text::with_baseline(
+            label,
+            Point::new(0, y),
+            self.graphics.character_smaller,
+            Baseline::Top,
         )
-        .translate(Point::new(
-            0,
-            (self.modifiers.left_gui() || self.modifiers.right_gui()) as i32 * -4,
-        ))
         .draw(display)?;
-        if self.modifiers.left_gui() || self.modifiers.right_gui() {
-            Rectangle::with_center(
-                display.bounding_box().center() + Point::new(45, 5),
-                Size::new(12, 2),
-            )
-            .into_styled(self.graphics.fill_style)
-            .draw(display)?;
-        }
 
-        Rectangle::with_corners(Point::new(123, 0), Point::new(127, 31))
+        let x0 = 24;
+        let x1 = self.graphics.bounding_box.size.width as i32 - 2;
+        Rectangle::with_corners(Point::new(x0, y + 1), Point::new(x1, y + 7))
             .into_styled(self.graphics.stroke_style)
             .draw(display)?;
 
-        Rectangle::with_corners(
-            Point::new(124, 32 - (self.battery as i32 * 32) / 100),
-            Point::new(126, 31),
+        let fill = (x1 - x0 - 2) * percent.min(100) as i32 / 100;
+        if fill > 0 {
+            Rectangle::with_corners(Point::new(x0 + 1, y + 2), Point::new(x0 + 1 + fill, y + 6))
+                .into_styled(self.graphics.fill_style)
+                .draw(display)?;
+        }
+
+        Ok(())
+    }
+
+    /// Draw `text` at row `y`, panning it left by `scroll` pixels when it is
+    /// wider than the panel so the whole line eventually cycles past.
+    fn draw_host_text<D>(
+        &self,
+        display: &mut D,
+        text: &str,
+        scroll: i32,
+        y: i32,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = B::Color>,
+    {
+        let char_width = self.graphics.character_smaller.font.character_size.width as i32;
+        let total = text.len() as i32 * char_width;
+        let x = if total <= self.graphics.bounding_box.size.width as i32 {
+            0
+        } else {
+            -scroll.rem_euclid(total + char_width)
+        };
+
+        Text::with_baseline(
+            text,
+            Point::new(x, y),
+            self.graphics.character_smaller,
+            Baseline::Top,
         )
-        .into_styled(self.graphics.fill_style)
         .draw(display)?;
 
-        display.flush().await
+        Ok(())
+    }
+
+    /// Render the host-telemetry screen: CPU and RAM bar gauges plus a scrolling
+    /// status line. Advances the scroll each tick so the text keeps moving.
+    async fn draw_host_stats(&mut self, display: &mut B::Target) -> Result<(), B::Error> {
+        let stats = match self.host_stats {
+            Some(stats) => stats,
+            None => return Ok(()),
+        };
+
+        display.clear(B::OFF)?;
+        self.draw_host_gauge(display, "CPU", stats.cpu, 0)?;
+        self.draw_host_gauge(display, "RAM", stats.ram, 10)?;
+        self.draw_host_text(display, stats.text(), self.scroll, 22)?;
+        self.scroll = self.scroll.wrapping_add(1);
+
+        self.backend.flush(display).await
+    }
+
+    /// Redraw only the logical regions of the status page whose source value
+    /// changed since the last render, then flush.
+    ///
+    /// Each redrawn primitive reports its bounding box into a [`DirtyRegion`].
+    /// Because only the changed regions are cleared and redrawn, the SSD1306's
+    /// buffered mode tracks a tight dirty window and [`DisplayBackend::flush`]
+    /// transmits just those pages — a battery-only change sends a handful of
+    /// columns. An unchanged frame accumulates nothing and skips the flush
+    /// entirely.
+    async fn draw_status(&mut self, display: &mut B::Target) -> Result<(), B::Error> {
+        let mut dirty = DirtyRegion::default();
+
+        if self.force_redraw {
+            display.clear(B::OFF)?;
+        }
+
+        if self.force_redraw || self.layer != self.drawn_layer {
+            self.graphics
+                .layer_region()
+                .into_styled(self.graphics.clear_style)
+                .draw(display)?;
+            draw_layer(display, &self.graphics, self.layer)?;
+            dirty.add(self.graphics.layer_region());
+            self.drawn_layer = self.layer;
+        }
+
+        for slot in 0..MODIFIER_SLOTS {
+            if self.force_redraw
+                || modifier_active(&self.modifiers, slot)
+                    != modifier_active(&self.drawn_modifiers, slot)
+            {
+                self.graphics
+                    .modifier_region(slot)
+                    .into_styled(self.graphics.clear_style)
+                    .draw(display)?;
+                draw_modifier(display, &self.graphics, &self.modifiers, slot)?;
+                dirty.add(self.graphics.modifier_region(slot));
+            }
+        }
+        self.drawn_modifiers = self.modifiers;
+
+        if self.force_redraw || self.battery != self.drawn_battery {
+            self.graphics
+                .battery_region()
+                .into_styled(self.graphics.clear_style)
+                .draw(display)?;
+            draw_battery(display, &self.graphics, self.battery)?;
+            dirty.add(self.graphics.battery_region());
+            self.drawn_battery = self.battery;
+        }
+
+        self.force_redraw = false;
+
+        match dirty.bounds() {
+            Some(_) => self.backend.flush(display).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Draw the active cycleable page. The default status page redraws
+    /// incrementally through [`draw_status`](Self::draw_status); the other pages
+    /// are full-frame, flushed only when the state or selected page changed
+    /// since the last render.
+    async fn draw_active(&mut self, display: &mut B::Target) -> Result<(), B::Error> {
+        if self.active == 0 {
+            return self.draw_status(display).await;
+        }
+
+        let state = ViewState {
+            layer: self.layer,
+            modifiers: self.modifiers,
+            battery: self.battery,
+            keypresses: self.keypresses,
+        };
+        let key = ViewKey::new(&state, self.active);
+        if !self.force_redraw && self.drawn_key == Some(key) {
+            return Ok(());
+        }
+
+        display.clear(B::OFF)?;
+        self.screens[self.active].draw(display, &self.graphics, &state)?;
+        self.drawn_key = Some(key);
+        self.force_redraw = false;
+
+        self.backend.flush(display).await
+    }
+
+    /// Pick between the boot splash, the active page, the host-telemetry screen,
+    /// and the idle screensaver based on events and timers.
+    async fn render(&mut self, display: &mut B::Target) -> Result<(), B::Error> {
+        if let Some(started) = self.splash_started {
+            if started.elapsed() < self.splash_duration {
+                return self.draw_splash(display).await;
+            }
+            self.splash_started = None;
+            self.force_redraw = true;
+        }
+
+        if self.last_activity.elapsed() >= self.idle_timeout {
+            self.screensaver_active = true;
+            return self.draw_screensaver(display).await;
+        }
+        if self.screensaver_active {
+            self.screensaver_active = false;
+            self.drawn_offset = None;
+            self.force_redraw = true;
+        }
+
+        if self.show_host {
+            self.draw_host_stats(display).await
+        } else {
+            self.draw_active(display).await
+        }
     }
 }
 
-impl<'a, SDA: Pin, SCL: Pin> Controller for DisplayController<'a, SDA, SCL> {
+impl<'a, B: DisplayBackend> Controller for DisplayController<'a, B> {
     type Event = ControllerEvent;
 
     async fn process_event(&mut self, event: Self::Event) {
         match event {
             ControllerEvent::Layer(layer) => {
                 self.layer = layer;
+                self.leave_host();
             }
             ControllerEvent::Modifier(modifiers) => {
                 self.modifiers = modifiers;
+                self.leave_host();
             }
             ControllerEvent::Battery(battery) => {
                 self.battery = battery;
+                self.leave_host();
             }
             _ => (),
         }
 
+        self.last_activity = Instant::now();
+
         if self.sub.len() < 2 {
             self.update().await;
         }
     }
 
     async fn next_message(&mut self) -> Self::Event {
-        self.sub.next_message_pure().await
+        // Merge the upstream controller channel with our local sidebar bus;
+        // sidebar events are handled inline since they are not `ControllerEvent`s,
+        // and only an upstream event is surfaced back to `process_event`.
+        loop {
+            match select(
+                self.sub.next_message_pure(),
+                self.sidebar.next_message_pure(),
+            )
+            .await
+            {
+                Either::First(event) => return event,
+                Either::Second(event) => self.process_sidebar(event).await,
+            }
+        }
     }
 }
 
-impl<'a, SDA: Pin, SCL: Pin> PollingController for DisplayController<'a, SDA, SCL> {
+impl<'a, B: DisplayBackend> PollingController for DisplayController<'a, B> {
     const INTERVAL: embassy_time::Duration = embassy_time::Duration::from_hz(30);
 
     async fn update(&mut self) {
-        match self.display.take() {
-            Some(mut display) => {
-                if let Ok(Ok(_)) = self.draw(&mut display).with_timeout(Duration::from_millis(100)).await {
-                    self.display = Some(display);
+        match self.target.take() {
+            Some(mut target) => {
+                if let Ok(Ok(_)) = self
+                    .render(&mut target)
+                    .with_timeout(Duration::from_millis(100))
+                    .await
+                {
+                    self.target = Some(target);
                 }
             }
             None => {
-                let i2c = unsafe {
-                    Twim::new(
-                        self.config.twim.clone_unchecked(),
-                        MyIrqs,
-                        self.config.sda.clone_unchecked(),
-                        self.config.scl.clone_unchecked(),
-                        Default::default(),
-                        &mut [],
-                    )
-                };
-                let interface = I2CDisplayInterface::new(i2c);
-                let mut display =
-                    Ssd1306Async::new(interface, DisplaySize128x32, DisplayRotation::Rotate0)
-                        .into_buffered_graphics_mode();
-                if let Ok(Ok(_)) = display
-                    .init()
-                    .with_timeout(Duration::from_secs(1))
-                    .await {
-                    self.display = Some(display);
+                if let Some(target) = self.backend.init().await {
+                    if !self.booted {
+                        self.booted = true;
+                        self.splash_started = Some(Instant::now());
+                    }
+                    self.force_redraw = true;
+                    self.drawn_offset = None;
+                    self.target = Some(target);
                 }
             }
         }
@@ -312,6 +1144,41 @@ impl<'a, SDA: Pin, SCL: Pin> PollingController for DisplayController<'a, SDA, SC
 mod keyboard_central {
     #[controller(poll)]
     fn display_controller() -> DisplayController {
-        DisplayController::new(p.TWISPI0, p.P0_17, p.P0_20)
+        DisplayController::new(
+            Ssd1306Backend::new(p.TWISPI0, p.P0_17, p.P0_20),
+            Duration::from_secs(60),
+            ScreenSaver::Drift,
+            Duration::from_secs(2),
+        )
+    }
+
+    /// Pump the vendor raw-HID endpoint into the sidebar bus. RMK delivers each
+    /// output report that the Vial handler does not claim to this hook;
+    /// [`route_host_stats_report`] keeps the host-telemetry packets and ignores
+    /// everything else, so it is safe to feed it the raw report verbatim.
+    #[raw_hid]
+    async fn host_stats(report: &[u8]) {
+        route_host_stats_report(report);
+    }
+
+    /// Key-event processor driving the WPM/keypress page. The macro inserts it
+    /// into the input-processing chain ahead of the HID writer, so it observes
+    /// every resolved key event; presses are counted through [`record_keypress`]
+    /// and the event is passed through unchanged.
+    #[processor]
+    async fn keypress_counter(event: &KeyboardEvent) -> ProcessResult {
+        if event.pressed {
+            record_keypress();
+        }
+        ProcessResult::Continue
+    }
+
+    /// Screen-cycle action, fired by chording `KeyCode::Escape` +
+    /// `KeyCode::Backspace` — a deliberate pair that does not come up while
+    /// typing. It advances the view manager to the next cycleable page through
+    /// [`cycle_screen`]. The chord is set by the `#[combo]` attribute below.
+    #[combo(keys = [KeyCode::Escape, KeyCode::Backspace])]
+    async fn cycle_screen_action() {
+        cycle_screen();
     }
 }